@@ -40,12 +40,113 @@ bitflags! {
         /// Surfaces created for this context will have an 8-bit stencil buffer, possibly using
         /// packed depth/stencil if the GL implementation supports it.
         const STENCIL               = 0x04;
-        /// The OpenGL compatibility profile will be used. If this is not present, the core profile
-        /// is used.
-        const COMPATIBILITY_PROFILE = 0x08;
+        /// The context will be created with robustness support, so that a GPU reset can be
+        /// detected via `Device::context_reset_status()` instead of silently corrupting
+        /// subsequent rendering. This requires `GL_ARB_robustness`/`WGL_ARB_create_context_robustness`
+        /// on desktop GL or `EGL_EXT_create_context_robustness` on EGL; if the extension is
+        /// unavailable, context creation falls back to a non-robust context rather than failing.
+        const ROBUST                = 0x10;
+        /// Surfaces created for this context will use an sRGB-encoded framebuffer, so that
+        /// fragment writes are automatically encoded from linear to sRGB. This requires selecting
+        /// an sRGB-capable config (`EGL_GL_COLORSPACE`/`GLX_FRAMEBUFFER_SRGB_CAPABLE_ARB`/the
+        /// equivalent CGL pixel-format attribute); if none is available, context creation falls
+        /// back to a linear framebuffer.
+        const SRGB                  = 0x20;
     }
 }
 
+/// The outcome of querying whether a robust context's GPU has been reset.
+///
+/// Returned by `Device::context_reset_status()`. Only meaningful for contexts created with
+/// `ContextAttributeFlags::ROBUST`; corresponds to the values reported by
+/// `glGetGraphicsResetStatus`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ResetStatus {
+    /// No reset has been detected since the context was created or since the last query.
+    NoError,
+    /// The current context lost some state because of a reset caused by this context.
+    GuiltyContextReset,
+    /// The current context lost some state because of a reset from another context or thread.
+    InnocentContextReset,
+    /// The current context lost some state because of a reset whose origin is unknown.
+    UnknownContextReset,
+}
+
+/// Which OpenGL profile a context should be created with.
+///
+/// This replaces the old `ContextAttributeFlags::COMPATIBILITY_PROFILE` bit, which left
+/// requesting e.g. GL 2.1 with the bit unset ambiguous between "core" and "legacy, profiles don't
+/// apply". Has no effect on OpenGL ES, which has no profile concept.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GlProfile {
+    /// Request the core profile, which removes all functionality deprecated in the requested GL
+    /// version. Context creation fails if the driver cannot provide a core profile at that
+    /// version.
+    Core,
+    /// Request the compatibility profile, which keeps functionality deprecated in the requested
+    /// GL version available.
+    Compatibility,
+    /// Try the core profile at the requested version first, and fall back to the compatibility
+    /// profile only if the driver rejects core. The profile actually obtained can be checked
+    /// afterwards with `current_context_uses_compatibility_profile()`.
+    Automatic,
+}
+
+/// A request for which OpenGL or OpenGL ES version a context should be created with.
+///
+/// Keep in mind that OpenGL and OpenGL ES have different version numbering schemes. Before
+/// filling in an `Exact` or `AtLeast` version, check the result of `Device::gl_api()`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum VersionRequest {
+    /// Request this exact version. Context creation fails if the driver cannot provide it.
+    Exact(GLVersion),
+    /// Request the highest version the driver can provide. Context creation tries progressively
+    /// lower versions (4.6, 4.5, … 3.2 for core GL; 3.2, 3.0, 2.0 for GLES) until one succeeds,
+    /// and the version actually obtained is written back into `ContextAttributes::version`.
+    Latest,
+    /// Request the highest version the driver can provide that is at least this version. Behaves
+    /// like `Latest` but fails outright if even this minimum cannot be satisfied.
+    AtLeast(GLVersion),
+}
+
+/// The pixel format used for the color buffer of surfaces created from a context.
+///
+/// Supersedes `ContextAttributeFlags::ALPHA` for choosing bit depth and type; `ALPHA` still
+/// controls whether the format carries an alpha channel at all, except for `Rgb10A2`, which
+/// ignores it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ColorFormat {
+    /// 8 bits per channel, unsigned normalized. The default, and the only format available on
+    /// backends that can't negotiate a deeper config.
+    Rgba8,
+    /// 10 bits per color channel and 2 bits of alpha, unsigned normalized. Selected via
+    /// `RGB10_A2`-class EGL/GLX configs or `MTLPixelFormatRGB10A2Unorm` on the CGL IOSurface path.
+    /// Ignores `ContextAttributeFlags::ALPHA`, since the format always carries (at most) 2 bits of
+    /// alpha.
+    Rgb10A2,
+    /// 16 bits per channel, floating point. Selected via `GLX_RGBA_FLOAT_BIT`-class configs or
+    /// `MTLPixelFormatRGBA16Float` on the CGL IOSurface path. Needed for HDR and wide-gamut
+    /// compositing, where 8 or 10 bits per channel aren't enough headroom.
+    Rgba16F,
+}
+
+/// Controls whether the GL pipeline is flushed when a context is released from the calling
+/// thread, e.g. by `Device::make_no_context_current()` or by binding a different context.
+///
+/// Corresponds to `EGL_KHR_context_flush_control`'s `EGL_CONTEXT_RELEASE_BEHAVIOR_KHR`; honored on
+/// the EGL and GLX backends when the extension is present, ignored otherwise (those backends
+/// always flush).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReleaseBehavior {
+    /// Flush the pipeline on release. This is the default, and the only behavior available when
+    /// `EGL_KHR_context_flush_control` is unsupported.
+    Flush,
+    /// Don't flush the pipeline on release. Useful for multithreaded renderers that explicitly
+    /// manage flushing themselves and want to avoid paying for an implicit flush every time a
+    /// context is juggled between worker threads.
+    NoFlush,
+}
+
 /// Attributes that control aspects of a context and/or surfaces created from that context.
 ///
 /// Similar to: <https://www.khronos.org/registry/webgl/specs/latest/1.0/#WEBGLCONTEXTATTRIBUTES>
@@ -55,17 +156,36 @@ pub struct ContextAttributes {
     ///
     /// Keep in mind that OpenGL and OpenGL ES have different version numbering schemes. Before
     /// filling in this field, check the result of `Device::gl_api()`.
-    pub version: GLVersion,
+    pub version: VersionRequest,
     /// Various flags.
     pub flags: ContextAttributeFlags,
+    /// Which OpenGL profile to request. Ignored on OpenGL ES.
+    pub profile: GlProfile,
+    /// The number of samples per pixel that surfaces created from this context should use for
+    /// multisample anti-aliasing.
+    ///
+    /// A value of 0 (the default) disables multisampling, and surfaces get a single-sample
+    /// color/depth/stencil attachment as usual. Any other value is treated as a request for at
+    /// least that many samples; each backend clamps it to the maximum sample count its config
+    /// enumeration actually advertises, so the value observed after context creation may be lower
+    /// than requested.
+    pub sample_count: u8,
+    /// The pixel format of the color buffer of surfaces created from this context.
+    pub color_format: ColorFormat,
+    /// Whether releasing this context from a thread implicitly flushes the GL pipeline.
+    pub release_behavior: ReleaseBehavior,
 }
 
 impl ContextAttributes {
     #[allow(dead_code)]
     pub(crate) fn zeroed() -> ContextAttributes {
         ContextAttributes {
-            version: GLVersion::new(0, 0),
+            version: VersionRequest::Exact(GLVersion::new(0, 0)),
             flags: ContextAttributeFlags::empty(),
+            profile: GlProfile::Core,
+            sample_count: 0,
+            color_format: ColorFormat::Rgba8,
+            release_behavior: ReleaseBehavior::Flush,
         }
     }
 }